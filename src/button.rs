@@ -13,6 +13,16 @@ const NORM_CLOSE: u8 = 1;
 const MANUAL: u8 = 0;
 const AUTO: u8 = 1;
 
+/// Auto-repeat (key-repeat) configuration for a held [Button].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum RepeatConfig {
+    /// No auto-repeat: a held button only ever reports the single hold event.
+    #[default]
+    NoRepeat,
+    /// Emits a repeat event `first` ms after the hold timeout, then every `interval` ms.
+    Repeat { first: u16, interval: u16 },
+}
+
 pub struct ButtonFlags {
     btn_deb: bool,
     hold: bool,
@@ -27,6 +37,8 @@ pub struct ButtonFlags {
     tick_mode: bool,
     no_pin: bool,
     counter_reset: bool,
+    repeat_flag: bool,
+    is_repeat: bool,
 }
 
 pub struct Button<P, D>
@@ -45,6 +57,8 @@ where
     timeout: u16,
     click_timeout: u16,
     step_timeout: u16,
+    repeat: RepeatConfig,
+    repeat_count: u32,
 }
 
 impl<P, D> Button<P, D>
@@ -68,6 +82,8 @@ where
                 tick_mode: false,
                 no_pin: false,
                 counter_reset: false,
+                repeat_flag: false,
+                is_repeat: false,
             },
             btn_counter: 0,
             last_counter: 0,
@@ -79,6 +95,8 @@ where
             timeout: 500,
             click_timeout: 500,
             step_timeout: 400,
+            repeat: RepeatConfig::NoRepeat,
+            repeat_count: 0,
         }
     }
 }
@@ -104,6 +122,14 @@ where
         self.step_timeout = step_timeout;
     }
 
+    pub fn set_repeat(&mut self, first: u16, interval: u16) {
+        self.repeat = RepeatConfig::Repeat { first, interval };
+    }
+
+    pub fn set_repeat_config(&mut self, repeat: RepeatConfig) {
+        self.repeat = repeat;
+    }
+
     pub fn set_tick_mode(&mut self, tick_mode: bool) {
         self.flags.tick_mode = tick_mode;
     }
@@ -236,20 +262,18 @@ where
         self.last_hold_counter
     }
 
-    /*pub fn is_step(&mut self, clicks: u8) -> bool {
-        /*if self.flags.tick_mode {
-            self.tick();
-        }*/
-        if self.btn_counter == clicks
-            && self.flags.step_flag
-            && (self.uptime.get().as_millis() - self.btn_timer >= self.step_timeout as u128)
-        {
-            self.btn_timer = self.uptime.get().as_millis();
+    pub fn is_repeat(&mut self) -> bool {
+        if self.flags.is_repeat {
+            self.flags.is_repeat = false;
             true
         } else {
             false
         }
-    }*/
+    }
+
+    pub fn repeat_count(&self) -> u32 {
+        self.repeat_count
+    }
 
     pub fn reset_states(&mut self) {
         self.flags.is_press = false;
@@ -258,8 +282,11 @@ where
         self.flags.is_holded = false;
         self.flags.step_flag = false;
         self.flags.counter = false;
+        self.flags.repeat_flag = false;
+        self.flags.is_repeat = false;
         self.last_hold_counter = 0;
         self.last_counter = 0;
+        self.repeat_count = 0;
     }
 
     /*pub fn tick_with_state(&mut self, state: bool) {
@@ -313,6 +340,8 @@ where
                 self.last_counter = 0;
                 self.btn_counter = 0;
                 self.flags.step_flag = false;
+                self.flags.repeat_flag = false;
+                self.repeat_count = 0;
             }
             if self.flags.one_click {
                 self.flags.one_click = false;
@@ -336,6 +365,19 @@ where
             self.btn_timer = this_mls;
         }
 
+        // автоповтор при удержании
+        if self.flags.step_flag {
+            if let RepeatConfig::Repeat { first, interval } = self.repeat {
+                let threshold = if self.flags.repeat_flag { interval } else { first };
+                if this_mls - self.btn_timer >= threshold as u128 {
+                    self.btn_timer = this_mls;
+                    self.flags.repeat_flag = true;
+                    self.flags.is_repeat = true;
+                    self.repeat_count += 1;
+                }
+            }
+        }
+
         // обработка накликивания
         if (this_mls - self.btn_timer >= self.click_timeout as u128)
             && (self.btn_counter != 0)
@@ -370,3 +412,418 @@ where
         };
     }
 }
+
+/// Policy used by [VirtualButton] to combine its member buttons into a single logical state.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CombinePolicy {
+    /// Fires if any member fires.
+    Any,
+    /// Requires all members to fire simultaneously (on the same tick).
+    All,
+}
+
+/// A logical button that aggregates several physical [Button]s, e.g. a panel button OR a
+/// gamepad button mapped to the same "select" action.
+///
+/// Members are ticked together in a single [TryTickWithResource::try_tick_with_resource] call
+/// and combined with a [CombinePolicy]. Value-style accessors ([VirtualButton::state],
+/// [VirtualButton::get_clicks]) follow a "latest input wins" rule: whichever member most
+/// recently reported an active state drives the reported value. Capacity is fixed at `N` so the
+/// aggregate stays `no_std`/alloc-free; members are added at runtime via [VirtualButton::try_push].
+pub struct VirtualButton<P, D, const N: usize>
+where
+    D: Direction,
+{
+    members: [Option<Button<P, D>>; N],
+    len: usize,
+    policy: CombinePolicy,
+    active: usize,
+    agg_press: bool,
+    agg_release: bool,
+    agg_click: bool,
+    agg_hold: bool,
+}
+
+impl<P, D, const N: usize> VirtualButton<P, D, N>
+where
+    D: Direction,
+{
+    pub fn new(policy: CombinePolicy) -> Self {
+        Self {
+            members: [(); N].map(|_| None),
+            len: 0,
+            policy,
+            active: 0,
+            agg_press: false,
+            agg_release: false,
+            agg_click: false,
+            agg_hold: false,
+        }
+    }
+
+    pub fn set_policy(&mut self, policy: CombinePolicy) {
+        self.policy = policy;
+    }
+
+    pub fn policy(&self) -> CombinePolicy {
+        self.policy
+    }
+
+    /// Adds a member button. Returns the button back as an error if capacity `N` is exhausted.
+    pub fn try_push(&mut self, button: Button<P, D>) -> Result<(), Button<P, D>> {
+        if self.len >= N {
+            return Err(button);
+        }
+        self.members[self.len] = Some(button);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<P, D, const N: usize> VirtualButton<P, D, N>
+where
+    P: InputPin,
+    D: Direction,
+{
+    /// One-shot: true if a press was registered on any tick since the last call, combined per
+    /// [CombinePolicy].
+    pub fn is_press(&mut self) -> bool {
+        if self.agg_press {
+            self.agg_press = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// One-shot: true if a release was registered on any tick since the last call, combined per
+    /// [CombinePolicy].
+    pub fn is_release(&mut self) -> bool {
+        if self.agg_release {
+            self.agg_release = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// One-shot: true if a click was registered on any tick since the last call, combined per
+    /// [CombinePolicy].
+    pub fn is_click(&mut self) -> bool {
+        if self.agg_click {
+            self.agg_click = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// True while held, combined per [CombinePolicy]. Not one-shot, mirrors [Button::is_hold].
+    pub fn is_hold(&mut self) -> bool {
+        self.agg_hold
+    }
+
+    pub fn state(&mut self) -> bool {
+        match self.members.get_mut(self.active).and_then(Option::as_mut) {
+            Some(button) => button.state(),
+            None => false,
+        }
+    }
+
+    pub fn get_clicks(&mut self) -> u32 {
+        match self.members.get_mut(self.active).and_then(Option::as_mut) {
+            Some(button) => button.get_clicks(),
+            None => 0,
+        }
+    }
+}
+
+impl<P, D, U, const N: usize> TryTickWithResource<&U> for VirtualButton<P, D, N>
+where
+    P: InputPin,
+    D: Direction,
+    U: Uptime,
+{
+    type Error = <P as InputPin>::Error;
+
+    // Member flags (`is_press`/`is_click`/...) latch until read, so combining them has to happen
+    // right here, once per tick, rather than whenever the caller later polls this button -
+    // otherwise a combo fired across different ticks could still read back as simultaneous.
+    fn try_tick_with_resource(&mut self, uptime: &U) -> Result<(), Self::Error> {
+        let mut press_any = false;
+        let mut press_all = true;
+        let mut release_any = false;
+        let mut release_all = true;
+        let mut click_any = false;
+        let mut click_all = true;
+        let mut hold_any = false;
+        let mut hold_all = true;
+
+        for (i, member) in self.members.iter_mut().enumerate().take(self.len) {
+            if let Some(button) = member {
+                button.try_tick_with_resource(uptime)?;
+
+                let press = button.is_press();
+                let release = button.is_release();
+                let click = button.is_click();
+                let hold = button.is_hold();
+
+                press_any |= press;
+                press_all &= press;
+                release_any |= release;
+                release_all &= release;
+                click_any |= click;
+                click_all &= click;
+                hold_any |= hold;
+                hold_all &= hold;
+
+                if button.state() {
+                    self.active = i;
+                }
+            }
+        }
+
+        let has_members = self.len != 0;
+        let policy = self.policy;
+        let combine = |any: bool, all: bool| match policy {
+            CombinePolicy::Any => any,
+            CombinePolicy::All => has_members && all,
+        };
+
+        self.agg_press |= combine(press_any, press_all);
+        self.agg_release |= combine(release_any, release_all);
+        self.agg_click |= combine(click_any, click_all);
+        self.agg_hold = combine(hold_any, hold_all);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P, D> Button<P, D>
+where
+    P: InputPin + embedded_hal_async::digital::Wait<Error = <P as InputPin>::Error>,
+    D: Direction,
+{
+    /// Waits until the button is pressed, running the same debounce state machine as
+    /// [`Button::try_tick_with_resource`] between the raw edge and the reported press.
+    pub async fn wait_for_press<U, T>(
+        &mut self,
+        uptime: &U,
+        delay: &mut T,
+    ) -> Result<(), <P as InputPin>::Error>
+    where
+        U: Uptime,
+        T: embedded_hal_async::delay::DelayNs,
+    {
+        loop {
+            // A button reads as pressed while its switch is disabled.
+            self.pin.wait_for_disabled().await?;
+            self.try_tick_with_resource(uptime)?;
+            if self.is_press() {
+                return Ok(());
+            }
+            delay.delay_ms(1).await;
+        }
+    }
+
+    /// Waits until the button is released, running the same debounce state machine as
+    /// [`Button::try_tick_with_resource`] between the raw edge and the reported release.
+    pub async fn wait_for_release<U, T>(
+        &mut self,
+        uptime: &U,
+        delay: &mut T,
+    ) -> Result<(), <P as InputPin>::Error>
+    where
+        U: Uptime,
+        T: embedded_hal_async::delay::DelayNs,
+    {
+        loop {
+            // Wait for the release level once a press is registered, for the press level before.
+            if self.btn_flag {
+                self.pin.wait_for_enabled().await?;
+            } else {
+                self.pin.wait_for_disabled().await?;
+            }
+            self.try_tick_with_resource(uptime)?;
+            if self.is_release() {
+                return Ok(());
+            }
+            delay.delay_ms(1).await;
+        }
+    }
+
+    /// Waits until a full click (press followed by release) is registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Requires the `async` feature and an executor (e.g. embassy) to drive the future.
+    /// loop {
+    ///     btn.wait_for_click(&uptime, &mut delay).await?;
+    ///     // handle the click
+    /// }
+    /// ```
+    pub async fn wait_for_click<U, T>(
+        &mut self,
+        uptime: &U,
+        delay: &mut T,
+    ) -> Result<(), <P as InputPin>::Error>
+    where
+        U: Uptime,
+        T: embedded_hal_async::delay::DelayNs,
+    {
+        loop {
+            self.try_tick_with_resource(uptime)?;
+            if self.is_click() {
+                return Ok(());
+            }
+            // Wait for the release level once a press is registered, for the press level before.
+            if self.btn_flag {
+                self.pin.wait_for_enabled().await?;
+            } else {
+                self.pin.wait_for_disabled().await?;
+            }
+            delay.delay_ms(1).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::direction::Normal;
+
+    struct CellPin<'a>(&'a Cell<bool>);
+
+    impl<'a> InputPin for CellPin<'a> {
+        type Error = Infallible;
+
+        fn is_high(&self) -> Result<bool, Self::Error> {
+            Ok(self.0.get())
+        }
+
+        fn is_low(&self) -> Result<bool, Self::Error> {
+            Ok(!self.0.get())
+        }
+    }
+
+    struct TestUptime(Cell<u64>);
+
+    impl crate::time::Uptime for TestUptime {
+        fn get(&self) -> core::time::Duration {
+            core::time::Duration::from_millis(self.0.get())
+        }
+    }
+
+    fn tick(
+        vbtn: &mut VirtualButton<CellPin<'_>, Normal, 2>,
+        uptime: &TestUptime,
+    ) {
+        uptime.0.set(uptime.0.get() + 1);
+        vbtn.try_tick_with_resource(uptime).unwrap();
+    }
+
+    // Drives a single member's pin through a full press-then-release so its `Button` latches a
+    // click, ticking `vbtn` (and thus every member) along the way.
+    fn click(vbtn: &mut VirtualButton<CellPin<'_>, Normal, 2>, uptime: &TestUptime, pin: &Cell<bool>) {
+        pin.set(true);
+        tick(vbtn, uptime);
+        tick(vbtn, uptime);
+        pin.set(false);
+        tick(vbtn, uptime);
+    }
+
+    #[test]
+    fn any_policy_fires_when_only_one_member_clicks() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+
+        let mut btn_a = Button::new(CellPin(&a));
+        btn_a.set_debounce(0);
+        let mut btn_b = Button::new(CellPin(&b));
+        btn_b.set_debounce(0);
+
+        let mut vbtn: VirtualButton<CellPin<'_>, Normal, 2> = VirtualButton::new(CombinePolicy::Any);
+        vbtn.try_push(btn_a).ok().unwrap();
+        vbtn.try_push(btn_b).ok().unwrap();
+
+        click(&mut vbtn, &uptime, &a);
+
+        assert!(vbtn.is_click());
+    }
+
+    #[test]
+    fn all_policy_does_not_fire_when_members_click_on_different_ticks() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+
+        let mut btn_a = Button::new(CellPin(&a));
+        btn_a.set_debounce(0);
+        let mut btn_b = Button::new(CellPin(&b));
+        btn_b.set_debounce(0);
+
+        let mut vbtn: VirtualButton<CellPin<'_>, Normal, 2> = VirtualButton::new(CombinePolicy::All);
+        vbtn.try_push(btn_a).ok().unwrap();
+        vbtn.try_push(btn_b).ok().unwrap();
+
+        click(&mut vbtn, &uptime, &a);
+        click(&mut vbtn, &uptime, &b);
+
+        assert!(!vbtn.is_click());
+    }
+
+    #[test]
+    fn all_policy_fires_when_members_click_on_the_same_tick() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+
+        let mut btn_a = Button::new(CellPin(&a));
+        btn_a.set_debounce(0);
+        let mut btn_b = Button::new(CellPin(&b));
+        btn_b.set_debounce(0);
+
+        let mut vbtn: VirtualButton<CellPin<'_>, Normal, 2> = VirtualButton::new(CombinePolicy::All);
+        vbtn.try_push(btn_a).ok().unwrap();
+        vbtn.try_push(btn_b).ok().unwrap();
+
+        a.set(true);
+        b.set(true);
+        tick(&mut vbtn, &uptime);
+        tick(&mut vbtn, &uptime);
+        a.set(false);
+        b.set(false);
+        tick(&mut vbtn, &uptime);
+
+        assert!(vbtn.is_click());
+    }
+
+    #[test]
+    fn zero_capacity_virtual_button_does_not_panic() {
+        let mut vbtn: VirtualButton<CellPin<'_>, Normal, 0> = VirtualButton::new(CombinePolicy::Any);
+        let uptime = TestUptime(Cell::new(0));
+
+        assert!(vbtn.try_tick_with_resource(&uptime).is_ok());
+        assert!(!vbtn.is_press());
+        assert!(!vbtn.is_click());
+        assert!(!vbtn.state());
+        assert_eq!(vbtn.get_clicks(), 0);
+
+        let pin = Cell::new(false);
+        let leftover = vbtn.try_push(Button::new(CellPin(&pin)));
+        assert!(leftover.is_err());
+    }
+}
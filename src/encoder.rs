@@ -0,0 +1,248 @@
+use embedded_hal::digital::v2::InputPin;
+
+use crate::{
+    direction::{Direction, Normal},
+    switch::{IntoSwitch, Switch},
+    tick::TryTickWithResource,
+    time::Uptime,
+};
+
+/// Rotation direction reported by an [Encoder], honoring the [Direction] type parameter.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Rotation {
+    Cw,
+    Ccw,
+}
+
+// Standard quadrature transition table, indexed by `(prev << 2) | current`. Valid single-step
+// transitions map to ±1; identical states and illegal double-transitions map to 0.
+const TABLE: [i8; 16] = [
+    0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0,
+];
+
+/// A quadrature rotary-encoder driver built on two digital inputs.
+///
+/// Decodes a standard two-channel incremental encoder by reading both channels each tick,
+/// forming a 2-bit code and looking up the transition in a 16-entry table. A full mechanical
+/// detent is four quadrature edges, so steps are accumulated in a sub-count and only reported
+/// as a [Rotation] once that sub-count crosses ±4, which keeps bounce near a detent boundary
+/// from producing spurious steps.
+///
+/// # Example
+///
+/// ```rust
+/// use vennix_hal::direction::Normal;
+/// use vennix_hal::encoder::Encoder;
+/// use vennix_hal::mock::Pin;
+///
+/// let mut encoder: Encoder<_, _, Normal> = Encoder::new(Pin::new(), Pin::new());
+/// assert_eq!(encoder.position(), 0);
+/// assert_eq!(encoder.poll(), None);
+/// ```
+pub struct Encoder<A, B, D = Normal>
+where
+    D: Direction,
+{
+    a: Switch<A, D>,
+    b: Switch<B, D>,
+    prev: u8,
+    sub_count: i8,
+    position: i32,
+    pending: Option<Rotation>,
+    debounce: u16,
+    deb_code: u8,
+    deb_timer: u128,
+}
+
+impl<A, B, D> Encoder<A, B, D>
+where
+    D: Direction,
+{
+    pub fn new(a: A, b: B) -> Self {
+        Self {
+            a: a.into_switch(),
+            b: b.into_switch(),
+            prev: 0,
+            sub_count: 0,
+            position: 0,
+            pending: None,
+            debounce: 0,
+            deb_code: 0,
+            deb_timer: 0,
+        }
+    }
+
+    /// Sets how long (ms) a channel code must be stable before it is latched as `prev`. `0`
+    /// (the default) disables debouncing.
+    pub fn set_debounce(&mut self, debounce: u16) {
+        self.debounce = debounce;
+    }
+
+    /// Returns the accumulated position in detents, positive for [Rotation::Cw].
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Takes the most recent rotation step not yet taken, if any. A step stays pending across
+    /// later ticks until it is taken or replaced by a newer step.
+    pub fn poll(&mut self) -> Option<Rotation> {
+        self.pending.take()
+    }
+
+    pub fn reset(&mut self) {
+        self.position = 0;
+        self.sub_count = 0;
+        self.pending = None;
+    }
+}
+
+impl<A, B, D, U> TryTickWithResource<&U> for Encoder<A, B, D>
+where
+    A: InputPin,
+    B: InputPin<Error = <A as InputPin>::Error>,
+    D: Direction,
+    U: Uptime,
+{
+    type Error = <A as InputPin>::Error;
+
+    fn try_tick_with_resource(&mut self, uptime: &U) -> Result<(), Self::Error> {
+        let a = self.a.try_check_is_enabled()?;
+        let b = self.b.try_check_is_enabled()?;
+        let code = ((a as u8) << 1) | (b as u8);
+
+        let this_mls = uptime.get().as_millis();
+
+        if self.debounce != 0 {
+            if code != self.deb_code {
+                self.deb_code = code;
+                self.deb_timer = this_mls;
+                return Ok(());
+            }
+            if this_mls - self.deb_timer < self.debounce as u128 {
+                return Ok(());
+            }
+        }
+
+        let index = ((self.prev << 2) | code) as usize;
+        let step = TABLE[index];
+        self.prev = code;
+
+        if step == 0 {
+            return Ok(());
+        }
+
+        self.sub_count += step;
+
+        if self.sub_count >= 4 {
+            self.sub_count -= 4;
+            let rotation = if D::IS_NORMAL { Rotation::Cw } else { Rotation::Ccw };
+            self.position += if matches!(rotation, Rotation::Cw) { 1 } else { -1 };
+            self.pending = Some(rotation);
+        } else if self.sub_count <= -4 {
+            self.sub_count += 4;
+            let rotation = if D::IS_NORMAL { Rotation::Ccw } else { Rotation::Cw };
+            self.position += if matches!(rotation, Rotation::Cw) { 1 } else { -1 };
+            self.pending = Some(rotation);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct CellPin<'a>(&'a Cell<bool>);
+
+    impl<'a> InputPin for CellPin<'a> {
+        type Error = core::convert::Infallible;
+
+        fn is_high(&self) -> Result<bool, Self::Error> {
+            Ok(self.0.get())
+        }
+
+        fn is_low(&self) -> Result<bool, Self::Error> {
+            Ok(!self.0.get())
+        }
+    }
+
+    struct TestUptime(Cell<u64>);
+
+    impl Uptime for TestUptime {
+        fn get(&self) -> core::time::Duration {
+            core::time::Duration::from_millis(self.0.get())
+        }
+    }
+
+    fn step(
+        encoder: &mut Encoder<CellPin<'_>, CellPin<'_>, Normal>,
+        a: &Cell<bool>,
+        b: &Cell<bool>,
+        uptime: &TestUptime,
+        a_state: bool,
+        b_state: bool,
+    ) -> Option<Rotation> {
+        a.set(a_state);
+        b.set(b_state);
+        encoder.try_tick_with_resource(uptime).unwrap();
+        encoder.poll()
+    }
+
+    #[test]
+    fn decodes_a_full_cw_detent() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+        let mut encoder: Encoder<_, _, Normal> = Encoder::new(CellPin(&a), CellPin(&b));
+
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, false, false), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, false), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, true), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, false, true), None);
+        assert_eq!(
+            step(&mut encoder, &a, &b, &uptime, false, false),
+            Some(Rotation::Cw)
+        );
+        assert_eq!(encoder.position(), 1);
+    }
+
+    #[test]
+    fn decodes_a_full_ccw_detent() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+        let mut encoder: Encoder<_, _, Normal> = Encoder::new(CellPin(&a), CellPin(&b));
+
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, false, true), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, true), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, false), None);
+        assert_eq!(
+            step(&mut encoder, &a, &b, &uptime, false, false),
+            Some(Rotation::Ccw)
+        );
+        assert_eq!(encoder.position(), -1);
+    }
+
+    #[test]
+    fn bounce_near_a_detent_boundary_does_not_step() {
+        let a = Cell::new(false);
+        let b = Cell::new(false);
+        let uptime = TestUptime(Cell::new(0));
+        let mut encoder: Encoder<_, _, Normal> = Encoder::new(CellPin(&a), CellPin(&b));
+
+        // Advance to 3/4 of a detent, then bounce back and forth across the boundary without
+        // ever completing the final quadrature edge.
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, false, false), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, false), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, true, true), None);
+        assert_eq!(step(&mut encoder, &a, &b, &uptime, false, true), None);
+        for _ in 0..5 {
+            assert_eq!(step(&mut encoder, &a, &b, &uptime, true, true), None);
+            assert_eq!(step(&mut encoder, &a, &b, &uptime, false, true), None);
+        }
+        assert_eq!(encoder.position(), 0);
+    }
+}
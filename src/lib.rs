@@ -15,6 +15,8 @@ pub mod prelude;
 
 pub mod button;
 
+pub mod encoder;
+
 pub mod mock {
     pub use pin::Pin;
 
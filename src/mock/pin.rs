@@ -2,7 +2,7 @@ use core::convert::Infallible;
 
 use embedded_hal::digital::v2::{InputPin, OutputPin, StatefulOutputPin};
 
-use crate::switch::State;
+use crate::switch::{FlexPin, Pull, State};
 
 pub struct Pin {
     state: State,
@@ -61,3 +61,15 @@ impl Default for Pin {
         Self::new()
     }
 }
+
+impl FlexPin for Pin {
+    fn set_as_output(&mut self) {
+        // The mock pin has no tri-state to model; its state is simply left as-is until the
+        // next `set_low`/`set_high` call.
+    }
+
+    fn set_as_input(&mut self, _pull: Pull) {
+        // The mock pin has no real pull resistors, so this is a no-op kept only to satisfy
+        // `FlexPin`.
+    }
+}
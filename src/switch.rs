@@ -1,6 +1,6 @@
 use core::{convert::Infallible, marker::PhantomData};
 
-use embedded_hal::digital::v2::{InputPin, OutputPin};
+use embedded_hal::digital::v2::{InputPin, OutputPin, StatefulOutputPin};
 
 use crate::direction::{Direction, Normal, Reverse};
 
@@ -280,6 +280,256 @@ impl<P> Switch<P, Reverse> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<P, D> Switch<P, D>
+where
+    P: embedded_hal_async::digital::Wait,
+    D: Direction,
+{
+    /// Waits asynchronously until the switch's pin reports it is enabled, honoring [Direction].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Requires the `async` feature and an executor (e.g. embassy) to drive the future.
+    /// use vennix_hal::switch::Switch;
+    ///
+    /// let mut switch = Switch::new(pin);
+    /// switch.wait_for_enabled().await?;
+    /// ```
+    pub async fn wait_for_enabled(&mut self) -> Result<(), P::Error> {
+        if D::IS_NORMAL {
+            self.inner.wait_for_high().await
+        } else {
+            self.inner.wait_for_low().await
+        }
+    }
+
+    /// Waits asynchronously until the switch's pin reports it is disabled, honoring [Direction].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Requires the `async` feature and an executor (e.g. embassy) to drive the future.
+    /// use vennix_hal::switch::Switch;
+    ///
+    /// let mut switch = Switch::new(pin);
+    /// switch.wait_for_disabled().await?;
+    /// ```
+    pub async fn wait_for_disabled(&mut self) -> Result<(), P::Error> {
+        if D::IS_NORMAL {
+            self.inner.wait_for_low().await
+        } else {
+            self.inner.wait_for_high().await
+        }
+    }
+}
+
+/// Pull-resistor configuration requested when a [FlexSwitch] is switched into input mode.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Pull {
+    None,
+    Up,
+    Down,
+}
+
+/// The mode a [FlexSwitch] is currently configured for.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FlexMode {
+    Output,
+    Input,
+}
+
+/// A pin which can be reconfigured between output and input mode at runtime.
+///
+/// Implement this for a concrete HAL pin type to back a [FlexSwitch].
+pub trait FlexPin: InputPin + OutputPin {
+    /// Configures the pin as a push-pull (or open-drain) output.
+    fn set_as_output(&mut self);
+
+    /// Configures the pin as an input with the given pull-resistor setting.
+    fn set_as_input(&mut self, pull: Pull);
+}
+
+/// Error returned by a [FlexSwitch] operation attempted in the wrong mode.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ModeError<E> {
+    /// The switch is not currently configured for the operation that was attempted.
+    WrongMode,
+    /// The underlying pin operation failed.
+    Pin(E),
+}
+
+/// A runtime-reconfigurable bidirectional pin wrapper.
+///
+/// Unlike [Switch], which is permanently an output or an input depending on the trait bounds
+/// satisfied by `P`, a `FlexSwitch` tracks its current [FlexMode] and can flip between driving
+/// and sensing the same GPIO at runtime, the same way a Flex pin backs both `Input` and `Output`
+/// in HAL crates that support it.
+pub struct FlexSwitch<P, D = Normal>
+where
+    P: FlexPin,
+{
+    inner: P,
+    mode: FlexMode,
+    _pd: PhantomData<D>,
+}
+
+impl<P, D> FlexSwitch<P, D>
+where
+    P: FlexPin,
+{
+    /// Creates a new instance configured as an output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vennix_hal::mock::Pin;
+    /// use vennix_hal::switch::FlexSwitch;
+    /// use vennix_hal::direction::Normal;
+    ///
+    /// let mut flex: FlexSwitch<_, Normal> = FlexSwitch::new_output(Pin::new());
+    /// flex.try_enable().unwrap();
+    /// ```
+    pub fn new_output(mut pin: P) -> Self {
+        pin.set_as_output();
+        Self {
+            inner: pin,
+            mode: FlexMode::Output,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Creates a new instance configured as an input with the given pull-resistor setting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vennix_hal::mock::Pin;
+    /// use vennix_hal::switch::{FlexSwitch, Pull};
+    /// use vennix_hal::direction::Normal;
+    ///
+    /// let mut flex: FlexSwitch<_, Normal> = FlexSwitch::new_input(Pin::new(), Pull::None);
+    /// let _ = flex.try_read_state();
+    ///
+    /// // Flip the same pin over to drive it instead of sensing it.
+    /// flex.set_as_output();
+    /// flex.try_enable().unwrap();
+    /// ```
+    pub fn new_input(mut pin: P, pull: Pull) -> Self {
+        pin.set_as_input(pull);
+        Self {
+            inner: pin,
+            mode: FlexMode::Input,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Consumes this switch and returns the inner pin.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Returns the currently configured mode.
+    pub fn mode(&self) -> FlexMode {
+        self.mode
+    }
+
+    /// Reconfigures the pin as an output.
+    pub fn set_as_output(&mut self) {
+        self.inner.set_as_output();
+        self.mode = FlexMode::Output;
+    }
+
+    /// Reconfigures the pin as an input with the given pull-resistor setting.
+    pub fn set_as_input(&mut self, pull: Pull) {
+        self.inner.set_as_input(pull);
+        self.mode = FlexMode::Input;
+    }
+}
+
+impl<P, D> FlexSwitch<P, D>
+where
+    P: FlexPin + OutputPin,
+    D: Direction,
+{
+    pub fn try_disable(&mut self) -> Result<(), ModeError<<P as OutputPin>::Error>> {
+        if self.mode != FlexMode::Output {
+            return Err(ModeError::WrongMode);
+        }
+        if D::IS_NORMAL {
+            self.inner.set_low().map_err(ModeError::Pin)
+        } else {
+            self.inner.set_high().map_err(ModeError::Pin)
+        }
+    }
+
+    pub fn try_enable(&mut self) -> Result<(), ModeError<<P as OutputPin>::Error>> {
+        if self.mode != FlexMode::Output {
+            return Err(ModeError::WrongMode);
+        }
+        if D::IS_NORMAL {
+            self.inner.set_high().map_err(ModeError::Pin)
+        } else {
+            self.inner.set_low().map_err(ModeError::Pin)
+        }
+    }
+
+    pub fn try_set_state(&mut self, state: State) -> Result<(), ModeError<<P as OutputPin>::Error>> {
+        match state {
+            State::Disabled => self.try_disable(),
+            State::Enabled => self.try_enable(),
+        }
+    }
+}
+
+impl<P, D> FlexSwitch<P, D>
+where
+    P: FlexPin + InputPin,
+    D: Direction,
+{
+    /// Reads the switch state. Returns [ModeError::WrongMode] while configured as output.
+    pub fn try_read_state(&self) -> Result<State, ModeError<<P as InputPin>::Error>> {
+        if self.mode != FlexMode::Input {
+            return Err(ModeError::WrongMode);
+        }
+        let enabled = if D::IS_NORMAL {
+            self.inner.is_high()
+        } else {
+            self.inner.is_low()
+        };
+        Ok(enabled.map_err(ModeError::Pin)?.into())
+    }
+}
+
+impl<P, D> FlexSwitch<P, D>
+where
+    P: FlexPin + StatefulOutputPin<Error = <P as InputPin>::Error>,
+    D: Direction,
+{
+    /// Reads the switch state regardless of mode: a true GPIO read while configured as input, or
+    /// a readback of the last written value while configured as output.
+    pub fn try_read_state_stateful(&self) -> Result<State, <P as InputPin>::Error> {
+        match self.mode {
+            FlexMode::Input => {
+                if D::IS_NORMAL {
+                    self.inner.is_high()
+                } else {
+                    self.inner.is_low()
+                }
+            }
+            FlexMode::Output => {
+                if D::IS_NORMAL {
+                    self.inner.is_set_high()
+                } else {
+                    self.inner.is_set_low()
+                }
+            }
+        }
+        .map(Into::into)
+    }
+}
+
 /// Provides the conversion of any pin into switch.
 pub trait IntoSwitch {
     /// Conversion any pin to a switch.